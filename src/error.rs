@@ -9,11 +9,21 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("received more data (`{0}`) than expected (`{1}`)")]
-    Overflow(u16, u16),
+    Overflow(u32, u32),
     #[error("missed frame; expected index `{0}`, received index `{1}`")]
     MissedFrame(u8, u8),
     #[error("internal buffer (`{0}`) is smaller than expected transfer length (`{1}`)")]
-    BufferTooSmall(u16, u16),
+    BufferTooSmall(u32, u32),
+    #[error("waiting for a Flow Control frame before sending more data")]
+    AwaitingFlowControl,
+    #[error("transfer aborted by receiver (Flow Control overflow or unknown status)")]
+    TransferAborted,
+    #[error("frame stream is desynced and cannot be decoded further")]
+    Desynced,
+    #[error("address extension byte mismatch: expected `{0}`, received `{1}`")]
+    AddressMismatch(u8, u8),
+    #[error("frame too short: need at least `{0}` bytes, got `{1}`")]
+    FrameTooShort(usize, usize),
 }
 
 /// Result type for ISO-TP library.
@@ -2,6 +2,8 @@
 
 /* ========== Imports ========== */
 use num_enum::FromPrimitive;
+use std::collections::VecDeque;
+use std::time::Duration;
 
 /* ========== Exports ========== */
 pub use crate::error::{Error, Result};
@@ -14,12 +16,27 @@ pub mod error;
 /// Max number of data bytes per frame.
 const MAX_DATA_BYTES_PER_FRAME: usize = 7;
 
-/// Number of bytes in a single CAN frame.
+/// Number of bytes in a single classic CAN frame.
 const NUM_BYTES_PER_FRAME: usize = 8;
 
-/// Maximum number of bytes that can be sent in a single transmission.
+/// Maximum number of bytes that can be sent in a single transmission using
+/// the classic 12-bit length field.
 const MAX_BYTES_PER_TRANSFER: usize = 4095;
 
+/// Maximum possible length of a CAN FD frame.
+const MAX_FD_FRAME_LEN: usize = 64;
+
+/// Maximum data length usable by the CAN FD Single Frame escape form
+/// (a 64-byte frame minus the PCI byte and the length byte).
+const MAX_FD_SINGLE_FRAME_LEN: usize = 62;
+
+/// Maximum number of bytes that can be sent in a single transmission using
+/// the CAN FD 32-bit escape length field.
+const MAX_FD_BYTES_PER_TRANSFER: usize = u32::MAX as usize;
+
+/// Valid CAN FD frame lengths (DLC values), in ascending order.
+const FD_FRAME_LENGTHS: [usize; 8] = [8, 12, 16, 20, 24, 32, 48, 64];
+
 /* ========== Enums ========== */
 
 /// Defines the Frame Type which determines what kind of data is contained in
@@ -54,6 +71,46 @@ pub enum FlowControlStatus {
     Unknown = 3,
 }
 
+/// Selects between classic CAN (8-byte frames) and CAN FD (up to 64-byte
+/// frames, with escape-length encoding for larger payloads) framing.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum FrameFormat {
+    /// Classic CAN: frames are always 8 bytes long.
+    #[default]
+    Classic,
+    /// CAN FD: frames may be any of the valid FD lengths, up to 64 bytes.
+    Fd,
+}
+
+/// Selects the ISO-TP addressing scheme used to lay out each frame.
+///
+/// Under Extended or Mixed addressing, byte 0 of every frame is an Address
+/// Extension (AE) byte identifying the logical channel sharing the CAN ID,
+/// and the PCI/length/index fields all shift right by one byte. This
+/// library only sees raw frame bytes, not CAN IDs, so Extended and Mixed
+/// addressing (which differ only in how the CAN ID itself is formed) are
+/// both represented by the `Extended` variant here.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum AddressingMode {
+    /// Normal addressing: the PCI byte starts at byte 0.
+    #[default]
+    Normal,
+    /// Extended or Mixed addressing: byte 0 is the Address Extension byte,
+    /// carrying the expected (decoder) or target (encoder) AE value.
+    Extended(u8),
+}
+
+/// Outcome of feeding a single frame to [`TransportDecoder::update`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The transfer is complete; carries the number of bytes received.
+    DataComplete(usize),
+    /// A Flow Control frame was received and decoded.
+    FlowControl(FlowControl),
+    /// The frame was consumed but the transfer is not yet complete.
+    Pending,
+}
+
 /* ========== Structs ========== */
 
 /// Represents a single packet in an ISO-TP exchange.
@@ -67,8 +124,7 @@ pub struct TransportData {
 
 /// Defines the Flow Control message, which is sent by the receiver in response
 /// to the First Frame message.
-#[allow(dead_code)]
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct FlowControl {
     /// Indicates if the transfer is allowed.
     status: FlowControlStatus,
@@ -82,17 +138,65 @@ pub struct FlowControl {
     separation_time: u8,
 }
 
+/// One CAN (or CAN FD) frame's raw bytes, as produced by
+/// [`TransportEncoder::next_frame`]. Sized to hold the largest supported
+/// frame (a CAN FD frame); classic CAN frames simply use the first 8 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    bytes: [u8; MAX_FD_FRAME_LEN],
+    len: usize,
+}
+
+impl Frame {
+    /// Create a zeroed frame of the given length.
+    fn new(len: usize) -> Self {
+        Self {
+            bytes: [0; MAX_FD_FRAME_LEN],
+            len,
+        }
+    }
+
+    /// The frame bytes as they should be placed on the bus.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl std::ops::Deref for Frame {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl PartialEq<[u8]> for Frame {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl<const M: usize> PartialEq<[u8; M]> for Frame {
+    fn eq(&self, other: &[u8; M]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
 /// Decode ISO-TP message.
 #[derive(Debug, Clone)]
 pub struct TransportDecoder<const N: usize> {
     /// Data packets collected so far.
     data: [u8; N],
     /// The expected number of bytes to receive.
-    expected_length: u16,
+    expected_length: u32,
     /// The number of bytes received so far.
-    current_length: u16,
+    current_length: u32,
     /// Track what the next expected index is.
     next_index: u8,
+    /// Classic CAN or CAN FD framing.
+    format: FrameFormat,
+    /// Normal, or Extended/Mixed with the expected address extension byte.
+    addressing: AddressingMode,
 }
 
 impl<const N: usize> TransportDecoder<N> {
@@ -108,91 +212,176 @@ impl<const N: usize> TransportDecoder<N> {
             expected_length: 0,
             current_length: 0,
             next_index: 0,
+            format: FrameFormat::Classic,
+            addressing: AddressingMode::Normal,
         }
     }
 
+    /// Select the CAN frame format this decoder should expect.
+    pub fn with_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Select the addressing mode this decoder should expect.
+    pub fn with_addressing(mut self, addressing: AddressingMode) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
     /// Maximum size of transfer that this decoder can accept.
     pub const fn max_size(&self) -> usize {
         N
     }
 
-    /// Update the decoder with a new frame. The input frame is expected to be
-    /// 8 bytes long.
-    /// * If the frame is complete and successfully decoded, returns Some(usize)
-    ///   to indicate the data is ready, where the return value is the number of
-    ///   bytes in the message.
-    /// * If the frame is not ready, returns None.
-    pub fn update(&mut self, frame: &[u8; NUM_BYTES_PER_FRAME]) -> Result<Option<usize>> {
-        // Check frame type (upper four bits of first byte)
-        let frame_type = FrameType::from(frame[0] >> 4);
+    /// Maximum transfer length representable by this decoder's frame format.
+    const fn max_transfer_size(&self) -> usize {
+        match self.format {
+            FrameFormat::Classic => MAX_BYTES_PER_TRANSFER,
+            FrameFormat::Fd => MAX_FD_BYTES_PER_TRANSFER,
+        }
+    }
+
+    /// Number of leading bytes occupied by the Address Extension byte: 1
+    /// under Extended/Mixed addressing, 0 under Normal addressing.
+    const fn ae_offset(&self) -> usize {
+        match self.addressing {
+            AddressingMode::Normal => 0,
+            AddressingMode::Extended(_) => 1,
+        }
+    }
+
+    /// Update the decoder with a new frame. Classic CAN frames are expected
+    /// to be 8 bytes long; CAN FD frames may be any valid FD length.
+    /// * If the transfer is complete, returns `Event::DataComplete(len)`.
+    /// * If a Flow Control frame was received, returns `Event::FlowControl`.
+    /// * Otherwise returns `Event::Pending`.
+    pub fn update(&mut self, frame: &[u8]) -> Result<Event> {
+        if frame.is_empty() {
+            return Err(Error::FrameTooShort(1, frame.len()));
+        }
+        if let AddressingMode::Extended(expected) = self.addressing {
+            if frame[0] != expected {
+                return Err(Error::AddressMismatch(expected, frame[0]));
+            }
+        }
+        let ae = self.ae_offset();
+        if frame.len() <= ae {
+            return Err(Error::FrameTooShort(ae + 1, frame.len()));
+        }
+
+        // Check frame type (upper four bits of the PCI byte)
+        let frame_type = FrameType::from(frame[ae] >> 4);
         match frame_type {
             FrameType::Single => {
-                // Data size is lower 4 bits of first byte
-                let data_length = (frame[0] & 0xF) as usize;
-                if data_length > MAX_DATA_BYTES_PER_FRAME {
-                    return Err(Error::Overflow(
-                        data_length as u16,
-                        MAX_BYTES_PER_TRANSFER as u16,
-                    ));
+                // Data size is lower 4 bits of the PCI byte, unless that
+                // nibble is 0 in FD mode, which signals the escape form
+                // (the next byte carries the real length).
+                let (data_offset, data_length) =
+                    if self.format == FrameFormat::Fd && (frame[ae] & 0xF) == 0 {
+                        if frame.len() <= ae + 1 {
+                            return Err(Error::FrameTooShort(ae + 2, frame.len()));
+                        }
+                        (ae + 2, frame[ae + 1] as usize)
+                    } else {
+                        (ae + 1, (frame[ae] & 0xF) as usize)
+                    };
+
+                let max_data_length = frame.len() - data_offset;
+                if data_length > max_data_length {
+                    return Err(Error::Overflow(data_length as u32, max_data_length as u32));
                 }
-                self.expected_length = data_length as u16;
-                self.current_length = data_length as u16;
-                self.data[0..data_length].copy_from_slice(&frame[1..(data_length + 1)]);
-                return Ok(Some(data_length));
+                self.expected_length = data_length as u32;
+                self.current_length = data_length as u32;
+                self.data[0..data_length]
+                    .copy_from_slice(&frame[data_offset..(data_offset + data_length)]);
+                Ok(Event::DataComplete(data_length))
             }
             FrameType::First => {
-                // Size is bytes 0.5 -> 2
-                let mut expected_length = 0;
-                expected_length += (frame[0] & 0xF) as u16;
+                if frame.len() <= ae + 1 {
+                    return Err(Error::FrameTooShort(ae + 2, frame.len()));
+                }
+
+                // Size is the PCI byte's low nibble plus the next byte,
+                // unless that 12-bit field is 0 in FD mode, which signals
+                // the 32-bit escape form in the following 4 bytes.
+                let mut expected_length = (frame[ae] & 0xF) as u32;
                 expected_length <<= 8;
-                expected_length += frame[1] as u16;
+                expected_length += frame[ae + 1] as u32;
+
+                let data_offset = if self.format == FrameFormat::Fd && expected_length == 0 {
+                    if frame.len() <= ae + 5 {
+                        return Err(Error::FrameTooShort(ae + 6, frame.len()));
+                    }
+                    expected_length = u32::from_be_bytes([
+                        frame[ae + 2],
+                        frame[ae + 3],
+                        frame[ae + 4],
+                        frame[ae + 5],
+                    ]);
+                    ae + 6
+                } else {
+                    ae + 2
+                };
 
-                // Make sure internal buffer can handle this transfer.
-                let max_size = self.max_size() as u16;
-                if expected_length > max_size {
+                // Make sure internal buffer and transfer size can handle this.
+                let max_size = self.max_size() as u32;
+                if expected_length > max_size || expected_length as usize > self.max_transfer_size()
+                {
                     return Err(Error::BufferTooSmall(max_size, expected_length));
                 }
                 self.expected_length = expected_length;
 
                 // The rest of this frame is the first chunk of data.
-                let data_length = 6; // TODO: constant?
-                self.data[0..data_length].copy_from_slice(&frame[2..]);
-                self.current_length = data_length as u16;
+                let data_length = frame.len() - data_offset;
+                self.data[0..data_length].copy_from_slice(&frame[data_offset..]);
+                self.current_length = data_length as u32;
                 self.next_index = 1;
+                Ok(Event::Pending)
             }
             FrameType::Consecutive => {
                 // Index increases by one every time, then rolls over after 15.
                 let expected_index = self.next_index & 0xF;
-                let actual_index = frame[0] & 0xF;
+                let actual_index = frame[ae] & 0xF;
                 if expected_index == actual_index {
                     self.next_index += 1;
 
                     // Copy data only up to expected length
-                    // TODO: Is this check necessary? The only limit is the internal buffer size.
                     let data_remaining = (self.expected_length - self.current_length) as usize;
-                    let data_length = std::cmp::min(MAX_DATA_BYTES_PER_FRAME, data_remaining);
+                    let data_length = std::cmp::min(frame.len() - ae - 1, data_remaining);
 
                     let data_start = self.current_length as usize;
                     let data_end = data_start + data_length;
-                    self.data[data_start..data_end].copy_from_slice(&frame[1..(data_length + 1)]);
+                    self.data[data_start..data_end]
+                        .copy_from_slice(&frame[(ae + 1)..(ae + 1 + data_length)]);
 
-                    self.current_length += data_length as u16;
+                    self.current_length += data_length as u32;
                     if self.ready() {
-                        return Ok(Some(self.current_length as usize));
+                        Ok(Event::DataComplete(self.current_length as usize))
                     } else {
-                        return Ok(None);
+                        Ok(Event::Pending)
                     }
                 } else {
                     // TODO: Missed a frame; what do we do?
-                    return Err(Error::MissedFrame(expected_index, actual_index));
+                    Err(Error::MissedFrame(expected_index, actual_index))
                 }
             }
             FrameType::FlowControl => {
-                // TODO (?)
+                if frame.len() <= ae + 2 {
+                    return Err(Error::FrameTooShort(ae + 3, frame.len()));
+                }
+
+                // PCI byte low nibble -> status, next byte -> block size,
+                // then -> separation time.
+                let status = FlowControlStatus::from(frame[ae] & 0xF);
+                let fc = FlowControl {
+                    status,
+                    block_size: frame[ae + 1],
+                    separation_time: frame[ae + 2],
+                };
+                Ok(Event::FlowControl(fc))
             }
         }
-
-        Ok(None)
     }
 
     /// Returns true if the data is ready to view.
@@ -210,9 +399,429 @@ impl<const N: usize> TransportDecoder<N> {
             None
         }
     }
+
+    /// Clear all in-progress transfer state so the decoder can be reused for
+    /// the next message. The configured `format` is left unchanged.
+    fn reset(&mut self) {
+        self.data = [0; N];
+        self.expected_length = 0;
+        self.current_length = 0;
+        self.next_index = 0;
+    }
+}
+
+/// Encode ISO-TP message.
+#[derive(Debug, Clone)]
+pub struct TransportEncoder<const N: usize> {
+    /// Data to be sent, copied in at construction time.
+    data: [u8; N],
+    /// Number of valid bytes in `data`.
+    length: usize,
+    /// Number of bytes already placed into an emitted frame.
+    offset: usize,
+    /// Track what the next consecutive-frame index is.
+    next_index: u8,
+    /// True once the final frame for this transfer has been emitted.
+    done: bool,
+    /// True while Consecutive Frames are blocked until a Continue Flow
+    /// Control frame is applied via [`Self::apply_flow_control`]: set after
+    /// the First Frame, after each block of `block_size` frames, and by a
+    /// Wait status.
+    awaiting_flow_control: bool,
+    /// Number of Consecutive Frames that may still be sent before another
+    /// Flow Control frame is required. `None` means unlimited (BS = 0).
+    frames_until_wait: Option<u8>,
+    /// Minimum delay to observe between Consecutive Frames (STmin).
+    separation_time: Duration,
+    /// Classic CAN or CAN FD framing.
+    format: FrameFormat,
+    /// Byte used to fill a frame out to its full length, if configured.
+    padding: Option<u8>,
+    /// Normal, or Extended/Mixed with the target address extension byte.
+    addressing: AddressingMode,
+}
+
+impl<const N: usize> TransportEncoder<N> {
+    pub const MAX_SEND_BYTES: usize = MAX_BYTES_PER_TRANSFER;
+}
+
+impl<const N: usize> TransportEncoder<N> {
+    /// Create a new encoder for the given payload.
+    pub fn new(data: &[u8]) -> Result<Self> {
+        if data.len() > N || data.len() > MAX_FD_BYTES_PER_TRANSFER {
+            return Err(Error::Overflow(
+                data.len() as u32,
+                std::cmp::min(N, MAX_FD_BYTES_PER_TRANSFER) as u32,
+            ));
+        }
+
+        let mut buffer = [0; N];
+        buffer[..data.len()].copy_from_slice(data);
+
+        Ok(Self {
+            data: buffer,
+            length: data.len(),
+            offset: 0,
+            next_index: 0,
+            done: false,
+            awaiting_flow_control: false,
+            frames_until_wait: None,
+            separation_time: Duration::ZERO,
+            format: FrameFormat::Classic,
+            padding: None,
+            addressing: AddressingMode::Normal,
+        })
+    }
+
+    /// Select the CAN frame format this encoder should produce.
+    pub fn with_format(mut self, format: FrameFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Select the addressing mode this encoder should produce.
+    pub fn with_addressing(mut self, addressing: AddressingMode) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
+    /// Configure the byte used to fill a Single Frame or the last
+    /// Consecutive Frame out to its full length. `None` (the default) leaves
+    /// any unused trailing bytes zeroed; frames are full length (8 bytes, or
+    /// the next valid FD length) either way.
+    pub fn with_padding(mut self, padding: Option<u8>) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Fill the unused tail of `frame` (after `content_len` bytes) with the
+    /// configured padding byte, if any.
+    fn pad(&self, frame: &mut Frame, content_len: usize) {
+        if let Some(byte) = self.padding {
+            frame.bytes[content_len..frame.len].fill(byte);
+        }
+    }
+
+    /// Maximum size of transfer that this encoder can accept.
+    pub const fn max_size(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the entire payload has already been emitted.
+    pub const fn done(&self) -> bool {
+        self.done
+    }
+
+    /// Number of leading bytes occupied by the Address Extension byte: 1
+    /// under Extended/Mixed addressing, 0 under Normal addressing.
+    const fn ae_offset(&self) -> usize {
+        match self.addressing {
+            AddressingMode::Normal => 0,
+            AddressingMode::Extended(_) => 1,
+        }
+    }
+
+    /// Write the Address Extension byte into `frame`, if configured.
+    fn write_addressing(&self, frame: &mut Frame) {
+        if let AddressingMode::Extended(ae) = self.addressing {
+            frame.bytes[0] = ae;
+        }
+    }
+
+    /// Smallest valid frame length (for the current format) that can hold
+    /// `needed` bytes.
+    fn frame_len(&self, needed: usize) -> usize {
+        match self.format {
+            FrameFormat::Classic => NUM_BYTES_PER_FRAME,
+            FrameFormat::Fd => *FD_FRAME_LENGTHS
+                .iter()
+                .find(|&&len| len >= needed)
+                .unwrap_or(&MAX_FD_FRAME_LEN),
+        }
+    }
+
+    /// Produce the next frame in the sequence, if any remain.
+    /// * If a frame was produced, returns `Some(frame)`.
+    /// * If the transfer is already complete, returns `None`.
+    pub fn next_frame(&mut self) -> Result<Option<Frame>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let ae = self.ae_offset();
+        let max_single_frame_len = match self.format {
+            FrameFormat::Classic => MAX_DATA_BYTES_PER_FRAME - ae,
+            FrameFormat::Fd => MAX_FD_SINGLE_FRAME_LEN - ae,
+        };
+
+        if self.offset == 0 {
+            if self.length <= max_single_frame_len {
+                // Single Frame: type nibble 0. Lengths up to 7 (minus any AE
+                // byte) use the low nibble directly; FD lengths beyond that
+                // use the escape form, where the low nibble is 0 and the
+                // next byte carries the real length.
+                let frame = if self.length <= MAX_DATA_BYTES_PER_FRAME - ae {
+                    let mut frame = Frame::new(self.frame_len(ae + 1 + self.length));
+                    self.write_addressing(&mut frame);
+                    frame.bytes[ae] = ((FrameType::Single as u8) << 4) | (self.length as u8);
+                    frame.bytes[(ae + 1)..(ae + 1 + self.length)]
+                        .copy_from_slice(&self.data[..self.length]);
+                    self.pad(&mut frame, ae + 1 + self.length);
+                    frame
+                } else {
+                    let mut frame = Frame::new(self.frame_len(ae + 2 + self.length));
+                    self.write_addressing(&mut frame);
+                    frame.bytes[ae] = (FrameType::Single as u8) << 4;
+                    frame.bytes[ae + 1] = self.length as u8;
+                    frame.bytes[(ae + 2)..(ae + 2 + self.length)]
+                        .copy_from_slice(&self.data[..self.length]);
+                    self.pad(&mut frame, ae + 2 + self.length);
+                    frame
+                };
+                self.offset = self.length;
+                self.done = true;
+                Ok(Some(frame))
+            } else {
+                // First Frame: type nibble 1. Lengths up to 4095 use the
+                // 12-bit length field; in FD mode, lengths beyond that use
+                // the 32-bit escape form (length field set to 0, real length
+                // in the following 4 bytes, data starting right after). The
+                // escape form only exists in FD mode, so a Classic-format
+                // payload this large cannot be represented.
+                if self.format == FrameFormat::Classic && self.length > MAX_BYTES_PER_TRANSFER {
+                    return Err(Error::Overflow(
+                        self.length as u32,
+                        MAX_BYTES_PER_TRANSFER as u32,
+                    ));
+                }
+                let escape = self.format == FrameFormat::Fd && self.length > MAX_BYTES_PER_TRANSFER;
+                let header_len = ae + if escape { 6 } else { 2 };
+                let max_frame_len = if self.format == FrameFormat::Fd {
+                    MAX_FD_FRAME_LEN
+                } else {
+                    NUM_BYTES_PER_FRAME
+                };
+                let data_length =
+                    std::cmp::min(max_frame_len - header_len, self.length - self.offset);
+
+                let mut frame = Frame::new(self.frame_len(header_len + data_length));
+                self.write_addressing(&mut frame);
+                if escape {
+                    frame.bytes[ae] = (FrameType::First as u8) << 4;
+                    frame.bytes[ae + 1] = 0;
+                    frame.bytes[(ae + 2)..(ae + 6)]
+                        .copy_from_slice(&(self.length as u32).to_be_bytes());
+                } else {
+                    frame.bytes[ae] =
+                        ((FrameType::First as u8) << 4) | ((self.length >> 8) as u8 & 0xF);
+                    frame.bytes[ae + 1] = (self.length & 0xFF) as u8;
+                }
+                frame.bytes[header_len..(header_len + data_length)]
+                    .copy_from_slice(&self.data[..data_length]);
+                self.pad(&mut frame, header_len + data_length);
+
+                self.offset = data_length;
+                self.next_index = 1;
+                self.awaiting_flow_control = true;
+                Ok(Some(frame))
+            }
+        } else {
+            // Consecutive Frame: type nibble 2, index cycles 0..=15.
+            if self.awaiting_flow_control {
+                return Err(Error::AwaitingFlowControl);
+            }
+
+            let max_frame_data = match self.format {
+                FrameFormat::Classic => MAX_DATA_BYTES_PER_FRAME - ae,
+                FrameFormat::Fd => MAX_FD_FRAME_LEN - 1 - ae,
+            };
+            let data_remaining = self.length - self.offset;
+            let data_length = std::cmp::min(max_frame_data, data_remaining);
+            let index = self.next_index & 0xF;
+
+            let mut frame = Frame::new(self.frame_len(ae + 1 + data_length));
+            self.write_addressing(&mut frame);
+            frame.bytes[ae] = ((FrameType::Consecutive as u8) << 4) | index;
+            frame.bytes[(ae + 1)..(ae + 1 + data_length)]
+                .copy_from_slice(&self.data[self.offset..(self.offset + data_length)]);
+            self.pad(&mut frame, ae + 1 + data_length);
+
+            self.offset += data_length;
+            self.next_index = self.next_index.wrapping_add(1);
+            if self.offset >= self.length {
+                self.done = true;
+            } else if let Some(remaining) = &mut self.frames_until_wait {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    self.awaiting_flow_control = true;
+                }
+            }
+
+            Ok(Some(frame))
+        }
+    }
+
+    /// Apply a received Flow Control frame, unblocking (or re-blocking)
+    /// transmission of Consecutive Frames according to its `status`.
+    pub fn apply_flow_control(&mut self, fc: FlowControl) -> Result<()> {
+        match fc.status {
+            FlowControlStatus::Continue => {
+                self.frames_until_wait = if fc.block_size == 0 {
+                    None
+                } else {
+                    Some(fc.block_size)
+                };
+                self.separation_time = separation_time_from_byte(fc.separation_time);
+                self.awaiting_flow_control = false;
+                Ok(())
+            }
+            FlowControlStatus::Wait => {
+                self.awaiting_flow_control = true;
+                Ok(())
+            }
+            FlowControlStatus::Overflow => Err(Error::TransferAborted),
+            FlowControlStatus::Unknown => Err(Error::TransferAborted),
+        }
+    }
+
+    /// Minimum delay the caller should wait between sending Consecutive
+    /// Frames, as requested by the most recent Flow Control frame.
+    pub const fn separation_time(&self) -> Duration {
+        self.separation_time
+    }
+
+    /// Returns true if the encoder is blocked waiting for a Flow Control
+    /// frame before it can emit more Consecutive Frames.
+    pub const fn awaiting_flow_control(&self) -> bool {
+        self.awaiting_flow_control
+    }
+}
+
+/// Streams raw frames into completed ISO-TP messages.
+///
+/// Wraps a single-shot [`TransportDecoder`] into a reusable pipeline, modeled
+/// on rustls's `MessageDeframer`: completed transfers are pushed onto an
+/// internal queue and the decoder is reset to receive the next message.
+/// Frames that the underlying decoder cannot make sense of (a Consecutive
+/// Frame with no prior First Frame, or a missed-frame index gap) latch the
+/// deframer into a permanently `desynced` state, since the byte stream can
+/// no longer be trusted to contain well-formed messages.
+#[derive(Debug, Clone)]
+pub struct IsoTpDeframer<const N: usize> {
+    /// Decoder for the message currently being received.
+    decoder: TransportDecoder<N>,
+    /// Completed messages not yet taken by the caller.
+    queue: VecDeque<Vec<u8>>,
+    /// True once a First Frame has been seen but the transfer it started is
+    /// not yet complete.
+    receiving: bool,
+    /// Set once an unrecoverable protocol violation has been observed.
+    desynced: bool,
+}
+
+impl<const N: usize> IsoTpDeframer<N> {
+    /// Create a new, empty deframer.
+    pub fn new() -> Self {
+        Self {
+            decoder: TransportDecoder::new(),
+            queue: VecDeque::new(),
+            receiving: false,
+            desynced: false,
+        }
+    }
+
+    /// Select the CAN frame format the underlying decoder should expect.
+    pub fn with_format(mut self, format: FrameFormat) -> Self {
+        self.decoder = self.decoder.with_format(format);
+        self
+    }
+
+    /// Select the addressing mode the underlying decoder should expect.
+    pub fn with_addressing(mut self, addressing: AddressingMode) -> Self {
+        self.decoder = self.decoder.with_addressing(addressing);
+        self
+    }
+
+    /// Returns true if an unrecoverable protocol violation has been seen and
+    /// no further frames can be pushed until the deframer is replaced.
+    pub const fn desynced(&self) -> bool {
+        self.desynced
+    }
+
+    /// Feed one raw frame (classic or FD) into the deframer.
+    ///
+    /// On completing a transfer, the decoded message is appended to the
+    /// internal queue and can be retrieved with [`Self::pop`]. Returns
+    /// `Err(Error::Desynced)` if called again after a prior unrecoverable
+    /// protocol violation.
+    pub fn push(&mut self, frame: &[u8]) -> Result<()> {
+        if self.desynced {
+            return Err(Error::Desynced);
+        }
+
+        if frame.is_empty() {
+            return Err(Error::FrameTooShort(1, frame.len()));
+        }
+        if let AddressingMode::Extended(expected) = self.decoder.addressing {
+            if frame[0] != expected {
+                return Err(Error::AddressMismatch(expected, frame[0]));
+            }
+        }
+
+        let ae = self.decoder.ae_offset();
+        if frame.len() <= ae {
+            return Err(Error::FrameTooShort(ae + 1, frame.len()));
+        }
+        let frame_type = FrameType::from(frame[ae] >> 4);
+        if frame_type == FrameType::Consecutive && !self.receiving {
+            self.desynced = true;
+            return Err(Error::Desynced);
+        }
+
+        match self.decoder.update(frame) {
+            Ok(Event::DataComplete(len)) => {
+                let data = self.decoder.data().unwrap_or(&[]);
+                self.queue.push_back(data[..len].to_vec());
+                self.decoder.reset();
+                self.receiving = false;
+                Ok(())
+            }
+            Ok(Event::Pending) => {
+                self.receiving = true;
+                Ok(())
+            }
+            Ok(Event::FlowControl(_)) => Ok(()),
+            Err(Error::MissedFrame(expected, actual)) => {
+                self.desynced = true;
+                Err(Error::MissedFrame(expected, actual))
+            }
+            Err(other) => Err(other),
+        }
+    }
+
+    /// Take the oldest completed message still in the queue, if any.
+    pub fn pop(&mut self) -> Option<Vec<u8>> {
+        self.queue.pop_front()
+    }
+}
+
+impl<const N: usize> Default for IsoTpDeframer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-pub struct TransportEncoder {}
+/// Convert an ISO-TP STmin byte into the minimum separation time it encodes.
+/// * `0x00..=0x7F`: that many milliseconds.
+/// * `0xF1..=0xF9`: 100-900 microseconds, in 100us steps.
+/// * Any other (reserved) value is treated as no additional delay.
+fn separation_time_from_byte(byte: u8) -> Duration {
+    match byte {
+        0x00..=0x7F => Duration::from_millis(byte as u64),
+        0xF1..=0xF9 => Duration::from_micros(100 * (byte - 0xF0) as u64),
+        _ => Duration::ZERO,
+    }
+}
 
 /* ========== Functions ========== */
 
@@ -229,9 +838,9 @@ mod tests {
         ];
 
         let mut decoder = TransportDecoder::<8>::new();
-        let size = decoder.update(&frame).unwrap().unwrap();
+        let event = decoder.update(&frame).unwrap();
 
-        assert_eq!(size, 7);
+        assert_eq!(event, Event::DataComplete(7));
         assert!(decoder.ready());
         assert_eq!(
             decoder.data().unwrap(),
@@ -248,9 +857,9 @@ mod tests {
         ];
 
         let mut decoder = TransportDecoder::<8>::new();
-        let size = decoder.update(&frame).unwrap().unwrap();
+        let event = decoder.update(&frame).unwrap();
 
-        assert_eq!(size, 6);
+        assert_eq!(event, Event::DataComplete(6));
         assert!(decoder.ready());
         assert_eq!(
             decoder.data().unwrap(),
@@ -277,22 +886,455 @@ mod tests {
 
         let mut decoder = TransportDecoder::<20>::new();
 
-        let result = decoder.update(&frame1).unwrap();
-        assert!(result.is_none());
+        let event = decoder.update(&frame1).unwrap();
+        assert_eq!(event, Event::Pending);
         assert!(!decoder.ready());
 
-        let result = decoder.update(&frame2).unwrap();
-        assert!(result.is_none());
+        let event = decoder.update(&frame2).unwrap();
+        assert_eq!(event, Event::Pending);
         assert!(!decoder.ready());
 
-        let result = decoder.update(&frame3).unwrap();
-        assert_eq!(result, Some(20));
+        let event = decoder.update(&frame3).unwrap();
+        assert_eq!(event, Event::DataComplete(20));
         assert!(decoder.ready());
         assert_eq!(
             decoder.data().unwrap(),
-            &[
-                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20
-            ]
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]
         );
     }
+
+    /// Test decoding a Flow Control frame.
+    #[test]
+    fn test_transport_decoder_flow_control() {
+        let frame = [
+            0x30, // Type = 3 (Flow Control), Status = 0 (Continue)
+            0x08, // Block Size = 8
+            0x0A, // Separation Time = 10ms
+            0, 0, 0, 0, 0,
+        ];
+
+        let mut decoder = TransportDecoder::<8>::new();
+        let event = decoder.update(&frame).unwrap();
+
+        assert_eq!(
+            event,
+            Event::FlowControl(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 8,
+                separation_time: 0x0A,
+            })
+        );
+    }
+
+    /// Test that an empty frame is rejected instead of panicking on `frame[0]`.
+    #[test]
+    fn test_transport_decoder_empty_frame() {
+        let mut decoder = TransportDecoder::<8>::new();
+        assert!(matches!(
+            decoder.update(&[]),
+            Err(Error::FrameTooShort(1, 0))
+        ));
+    }
+
+    /// Test that a truncated CAN FD First Frame escape header is rejected
+    /// instead of panicking while reading the 32-bit length bytes.
+    #[test]
+    fn test_transport_decoder_truncated_fd_escape_header() {
+        let mut decoder = TransportDecoder::<8>::new().with_format(FrameFormat::Fd);
+        let frame = [0x10, 0x00, 0x00, 0x00];
+        assert!(decoder.update(&frame).is_err());
+    }
+
+    /// Test encoding a Single Frame message of length 7.
+    #[test]
+    fn test_transport_encoder_single1() {
+        let data = [0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+        let mut encoder = TransportEncoder::<8>::new(&data).unwrap();
+
+        let frame = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame, [0x07, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+        assert!(encoder.done());
+        assert!(encoder.next_frame().unwrap().is_none());
+    }
+
+    /// Test encoding a Multiple Frame message of length 20.
+    #[test]
+    fn test_transport_encoder_multi1() {
+        let data: [u8; 20] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        ];
+        let mut encoder = TransportEncoder::<20>::new(&data).unwrap();
+
+        let frame1 = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame1, [0x10, 0x14, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        assert!(!encoder.done());
+
+        encoder
+            .apply_flow_control(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 0,
+                separation_time: 0,
+            })
+            .unwrap();
+
+        let frame2 = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame2, [0x21, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D]);
+        assert!(!encoder.done());
+
+        let frame3 = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame3, [0x22, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14]);
+        assert!(encoder.done());
+
+        assert!(encoder.next_frame().unwrap().is_none());
+    }
+
+    /// Test that a payload larger than the internal buffer is rejected.
+    #[test]
+    fn test_transport_encoder_overflow() {
+        let data = [0u8; 10];
+        let result = TransportEncoder::<8>::new(&data);
+        assert!(result.is_err());
+    }
+
+    /// Test that a payload over 4095 bytes is rejected by a Classic-format
+    /// encoder instead of silently emitting a CAN FD escape First Frame.
+    #[test]
+    fn test_transport_encoder_classic_rejects_over_4095() {
+        let data = vec![0u8; 5000];
+        let mut encoder = TransportEncoder::<5000>::new(&data).unwrap();
+        assert!(matches!(
+            encoder.next_frame(),
+            Err(Error::Overflow(5000, 4095))
+        ));
+    }
+
+    /// Test that Consecutive Frames are blocked until Flow Control is applied.
+    #[test]
+    fn test_transport_encoder_flow_control_gate() {
+        let data: [u8; 20] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        ];
+        let mut encoder = TransportEncoder::<20>::new(&data).unwrap();
+
+        let _first_frame = encoder.next_frame().unwrap().unwrap();
+        assert!(encoder.awaiting_flow_control());
+        assert!(matches!(
+            encoder.next_frame(),
+            Err(Error::AwaitingFlowControl)
+        ));
+
+        encoder
+            .apply_flow_control(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 0,
+                separation_time: 0x0A,
+            })
+            .unwrap();
+        assert!(!encoder.awaiting_flow_control());
+        assert_eq!(encoder.separation_time(), Duration::from_millis(10));
+
+        let frame2 = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame2, [0x21, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D]);
+        let frame3 = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame3, [0x22, 0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14]);
+        assert!(encoder.done());
+    }
+
+    /// Test that a non-zero block size re-blocks transmission after BS frames.
+    #[test]
+    fn test_transport_encoder_flow_control_block_size() {
+        let data: [u8; 20] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        ];
+        let mut encoder = TransportEncoder::<20>::new(&data).unwrap();
+        let _first_frame = encoder.next_frame().unwrap().unwrap();
+
+        encoder
+            .apply_flow_control(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 1,
+                separation_time: 0,
+            })
+            .unwrap();
+
+        let _frame2 = encoder.next_frame().unwrap().unwrap();
+        assert!(encoder.awaiting_flow_control());
+        assert!(matches!(
+            encoder.next_frame(),
+            Err(Error::AwaitingFlowControl)
+        ));
+    }
+
+    /// Test that an Overflow Flow Control frame aborts the transfer.
+    #[test]
+    fn test_transport_encoder_flow_control_overflow() {
+        let data: [u8; 20] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        ];
+        let mut encoder = TransportEncoder::<20>::new(&data).unwrap();
+        let _first_frame = encoder.next_frame().unwrap().unwrap();
+
+        let result = encoder.apply_flow_control(FlowControl {
+            status: FlowControlStatus::Overflow,
+            block_size: 0,
+            separation_time: 0,
+        });
+        assert!(matches!(result, Err(Error::TransferAborted)));
+    }
+
+    /// Test encoding a payload of 20 bytes as a single CAN FD frame, which
+    /// fits entirely in the Single Frame escape form.
+    #[test]
+    fn test_transport_encoder_fd_single_escape() {
+        let data: Vec<u8> = (1..=20).collect();
+        let mut encoder = TransportEncoder::<20>::new(&data)
+            .unwrap()
+            .with_format(FrameFormat::Fd);
+
+        let frame = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame.as_bytes().len(), 24); // Rounded up to the nearest FD length.
+        assert_eq!(frame.as_bytes()[0], 0x00); // Escape form: low nibble 0.
+        assert_eq!(frame.as_bytes()[1], 20); // Real length in byte[1].
+        assert_eq!(&frame.as_bytes()[2..22], data.as_slice());
+        assert!(encoder.done());
+    }
+
+    /// Test that a CAN FD decoder can round-trip a payload encoded by a CAN
+    /// FD encoder, using the Single Frame escape form.
+    #[test]
+    fn test_transport_fd_roundtrip_single_escape() {
+        let data: Vec<u8> = (1..=20).collect();
+        let mut encoder = TransportEncoder::<20>::new(&data)
+            .unwrap()
+            .with_format(FrameFormat::Fd);
+        let frame = encoder.next_frame().unwrap().unwrap();
+
+        let mut decoder = TransportDecoder::<20>::new().with_format(FrameFormat::Fd);
+        let event = decoder.update(frame.as_bytes()).unwrap();
+
+        assert_eq!(event, Event::DataComplete(20));
+        assert_eq!(decoder.data().unwrap(), data.as_slice());
+    }
+
+    /// Test that a CAN FD transfer larger than a Single Frame uses the First
+    /// and Consecutive Frame forms, carrying far more data per frame than
+    /// classic CAN, and round-trips correctly end to end.
+    #[test]
+    fn test_transport_fd_roundtrip_multi() {
+        let data: Vec<u8> = (0..100).map(|i| i as u8).collect();
+        let mut encoder = TransportEncoder::<100>::new(&data)
+            .unwrap()
+            .with_format(FrameFormat::Fd);
+        let mut decoder = TransportDecoder::<100>::new().with_format(FrameFormat::Fd);
+
+        let first_frame = encoder.next_frame().unwrap().unwrap();
+        // A 64-byte FD frame carries 62 data bytes in the First Frame.
+        assert_eq!(first_frame.as_bytes().len(), 64);
+        assert_eq!(
+            decoder.update(first_frame.as_bytes()).unwrap(),
+            Event::Pending
+        );
+
+        encoder
+            .apply_flow_control(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 0,
+                separation_time: 0,
+            })
+            .unwrap();
+
+        while let Some(frame) = encoder.next_frame().unwrap() {
+            let event = decoder.update(frame.as_bytes()).unwrap();
+            if let Event::DataComplete(len) = event {
+                assert_eq!(len, 100);
+            }
+        }
+
+        assert!(decoder.ready());
+        assert_eq!(decoder.data().unwrap(), data.as_slice());
+    }
+
+    /// Test that a Single Frame is padded out to 8 bytes with the configured
+    /// padding byte, and that the decoder ignores the padding.
+    #[test]
+    fn test_transport_encoder_padding_single() {
+        let data = [0x11, 0x22, 0x33];
+        let mut encoder = TransportEncoder::<8>::new(&data)
+            .unwrap()
+            .with_padding(Some(0xAA));
+
+        let frame = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(
+            frame.as_bytes(),
+            &[0x03, 0x11, 0x22, 0x33, 0xAA, 0xAA, 0xAA, 0xAA]
+        );
+
+        let mut decoder = TransportDecoder::<8>::new();
+        let event = decoder.update(frame.as_bytes()).unwrap();
+        assert_eq!(event, Event::DataComplete(3));
+        assert_eq!(decoder.data().unwrap(), &data);
+    }
+
+    /// Test that the last Consecutive Frame of a classic transfer is padded
+    /// out to 8 bytes, and that the decoder ignores the padding.
+    #[test]
+    fn test_transport_encoder_padding_last_consecutive() {
+        let data: [u8; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut encoder = TransportEncoder::<9>::new(&data)
+            .unwrap()
+            .with_padding(Some(0xAA));
+        let mut decoder = TransportDecoder::<9>::new();
+
+        let first_frame = encoder.next_frame().unwrap().unwrap();
+        decoder.update(first_frame.as_bytes()).unwrap();
+
+        encoder
+            .apply_flow_control(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 0,
+                separation_time: 0,
+            })
+            .unwrap();
+
+        let last_frame = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(
+            last_frame.as_bytes(),
+            &[0x21, 0x07, 0x08, 0x09, 0xAA, 0xAA, 0xAA, 0xAA]
+        );
+        assert!(encoder.done());
+
+        let event = decoder.update(last_frame.as_bytes()).unwrap();
+        assert_eq!(event, Event::DataComplete(9));
+        assert_eq!(decoder.data().unwrap(), &data);
+    }
+
+    /// Test that the deframer yields a Single Frame message as soon as it
+    /// arrives.
+    #[test]
+    fn test_deframer_single_frame() {
+        let mut deframer = IsoTpDeframer::<8>::new();
+        deframer
+            .push(&[0x03, 0x11, 0x22, 0x33, 0, 0, 0, 0])
+            .unwrap();
+        assert_eq!(deframer.pop().unwrap(), vec![0x11, 0x22, 0x33]);
+        assert_eq!(deframer.pop(), None);
+        assert!(!deframer.desynced());
+    }
+
+    /// Test that the deframer assembles a multi-frame message and is ready
+    /// to receive the next one immediately afterwards.
+    #[test]
+    fn test_deframer_multi_frame() {
+        let mut deframer = IsoTpDeframer::<9>::new();
+        deframer.push(&[0x10, 0x09, 1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(deframer.pop(), None);
+        deframer.push(&[0x21, 7, 8, 9, 0, 0, 0, 0]).unwrap();
+        assert_eq!(deframer.pop().unwrap(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // The decoder was reset, so a fresh Single Frame message can follow.
+        deframer.push(&[0x02, 0xAA, 0xBB, 0, 0, 0, 0, 0]).unwrap();
+        assert_eq!(deframer.pop().unwrap(), vec![0xAA, 0xBB]);
+    }
+
+    /// Test that a stray Consecutive Frame with no prior First Frame
+    /// desyncs the deframer.
+    #[test]
+    fn test_deframer_desync_on_stray_consecutive() {
+        let mut deframer = IsoTpDeframer::<8>::new();
+        assert!(matches!(
+            deframer.push(&[0x21, 1, 2, 3, 4, 5, 6, 7]),
+            Err(Error::Desynced)
+        ));
+        assert!(deframer.desynced());
+        assert!(matches!(
+            deframer.push(&[0x03, 0x11, 0x22, 0x33, 0, 0, 0, 0]),
+            Err(Error::Desynced)
+        ));
+    }
+
+    /// Test that a missed-frame index gap desyncs the deframer.
+    #[test]
+    fn test_deframer_desync_on_missed_frame() {
+        let mut deframer = IsoTpDeframer::<9>::new();
+        deframer.push(&[0x10, 0x09, 1, 2, 3, 4, 5, 6]).unwrap();
+        assert!(matches!(
+            deframer.push(&[0x22, 7, 8, 9, 0, 0, 0, 0]),
+            Err(Error::MissedFrame(1, 2))
+        ));
+        assert!(deframer.desynced());
+    }
+
+    /// Test that a Consecutive-Frame-shaped frame for a *different* logical
+    /// channel (a foreign Address Extension byte) under Extended addressing
+    /// is rejected as a recoverable `AddressMismatch` rather than
+    /// permanently desyncing this channel's deframer.
+    #[test]
+    fn test_deframer_foreign_ae_does_not_desync() {
+        let mut deframer =
+            IsoTpDeframer::<8>::new().with_addressing(AddressingMode::Extended(0x55));
+        assert!(matches!(
+            deframer.push(&[0xAA, 0x21, 1, 2, 3, 4, 5, 6]),
+            Err(Error::AddressMismatch(0x55, 0xAA))
+        ));
+        assert!(!deframer.desynced());
+    }
+
+    /// Test that Extended addressing shifts the Single Frame payload right
+    /// by one byte, leaving 6 data bytes instead of 7.
+    #[test]
+    fn test_transport_encoder_extended_addressing_single() {
+        let data = [0x11, 0x22, 0x33];
+        let mut encoder = TransportEncoder::<8>::new(&data)
+            .unwrap()
+            .with_addressing(AddressingMode::Extended(0xAB));
+
+        let frame = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(frame.as_bytes(), &[0xAB, 0x03, 0x11, 0x22, 0x33, 0, 0, 0]);
+    }
+
+    /// Test that a full round trip of a multi-frame transfer under Extended
+    /// addressing decodes correctly, with 5 data bytes in the First Frame
+    /// and 6 in each Consecutive Frame.
+    #[test]
+    fn test_transport_extended_addressing_roundtrip_multi() {
+        let data: [u8; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+        let mut encoder = TransportEncoder::<11>::new(&data)
+            .unwrap()
+            .with_addressing(AddressingMode::Extended(0x55));
+        let mut decoder =
+            TransportDecoder::<11>::new().with_addressing(AddressingMode::Extended(0x55));
+
+        let first_frame = encoder.next_frame().unwrap().unwrap();
+        assert_eq!(first_frame.as_bytes(), &[0x55, 0x10, 0x0B, 1, 2, 3, 4, 5]);
+        assert_eq!(
+            decoder.update(first_frame.as_bytes()).unwrap(),
+            Event::Pending
+        );
+
+        encoder
+            .apply_flow_control(FlowControl {
+                status: FlowControlStatus::Continue,
+                block_size: 0,
+                separation_time: 0,
+            })
+            .unwrap();
+
+        while let Some(frame) = encoder.next_frame().unwrap() {
+            decoder.update(frame.as_bytes()).unwrap();
+        }
+
+        assert!(decoder.ready());
+        assert_eq!(decoder.data().unwrap(), &data);
+    }
+
+    /// Test that an unexpected address extension byte is rejected.
+    #[test]
+    fn test_transport_decoder_address_mismatch() {
+        let mut decoder =
+            TransportDecoder::<8>::new().with_addressing(AddressingMode::Extended(0xAB));
+        assert!(matches!(
+            decoder.update(&[0xCD, 0x03, 0x11, 0x22, 0x33, 0, 0, 0]),
+            Err(Error::AddressMismatch(0xAB, 0xCD))
+        ));
+    }
 }